@@ -3,9 +3,11 @@
 
 use proc_macro::TokenStream;
 use quote::{quote, format_ident};
-use syn::{parse_macro_input, DeriveInput, Data, Fields};
+use syn::{parse_macro_input, DeriveInput, Data, Fields, Ident};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
 
-use crate::parse::{is_option, is_vec, option_inner_type, get_skip_expr, get_setter_set_name, get_setter_push_name, get_setter_push_many_name};
+use crate::parse::{is_option, is_vec, option_inner_type, get_skip_expr, get_setter_set_name, get_setter_push_name, get_setter_push_many_name, is_typestate, get_setter_name, get_default_expr, get_setter_into, has_setter_into_flag, is_legacy_error, get_validate_path, get_custom_field, get_perform_path, has_setter_try_into_flag, get_setter_try_name};
 
 /// Main code generation entry point for the AutoBuilder macro.
 /// Generates the builder struct, all setters, and the build method.
@@ -24,13 +26,46 @@ pub fn expand_autobuilder(input: TokenStream) -> TokenStream {
         _ => panic!("AutoBuilder only supports structs"),
     };
 
+    // `#[builder(typestate)]` opts into the const-generic typestate builder,
+    // where forgetting a required field is a compile error rather than a
+    // runtime `Err`. It is generated by a dedicated expansion since the two
+    // modes share almost nothing beyond field classification.
+    if is_typestate(&input.attrs) {
+        return expand_typestate_builder(struct_name, builder_name, fields);
+    }
+
+    // Struct-level `#[builder(setter(into))]` makes the single-value setters
+    // (regular, `Option` and Vec push) accept `impl Into<T>` by default; a
+    // field can still opt in on its own even when the struct doesn't. The
+    // Vec extend/replace setters and `field(...)` setters keep their exact
+    // parameter types.
+    let struct_default_into = has_setter_into_flag(&input.attrs);
+
+    // `#[builder(legacy_error)]` keeps the old `Result<Self, String>` build()
+    // signature around for callers that haven't moved to the generated
+    // `*BuilderError` type yet.
+    let legacy_error = is_legacy_error(&input.attrs);
+    let builder_error_name = format_ident!("{}BuilderError", struct_name);
+
+    // Turns a `Result<(), E>` from a validation hook into the builder's
+    // error type, for splicing right after the call that produced it.
+    let validation_map_err = |legacy_error: bool| {
+        if legacy_error {
+            quote! { .map_err(|e| e.to_string())? }
+        } else {
+            quote! { .map_err(|e| #builder_error_name::Validation(e.to_string()))? }
+        }
+    };
+
     // These vectors collect the generated code for the builder struct
     let mut builder_fields = Vec::new(); // Fields in the builder struct
     let mut setters = Vec::new();        // Setter methods
-    let mut build_fields = Vec::new();   // Fields for the final build() call
-    let mut field_idents = Vec::new();   // Field names for builder initialization
+    let mut value_bindings = Vec::new(); // `let field = ...;` (+ validation) statements, in field order
+    let mut struct_fields = Vec::new();  // Fields for the final struct literal
+    let mut new_field_inits = Vec::new(); // `#name: <init expr>` for new(), one per stored field
     let mut skipped_with_value = Vec::new();    // Skipped fields with a value
     let mut skipped_without_value = Vec::new(); // Skipped fields without a value
+    let mut required_field_names = Vec::new(); // Names of required fields, for missing-field checks
 
     // Iterate over each field in the struct
     for f in fields.iter() {
@@ -39,8 +74,8 @@ pub fn expand_autobuilder(input: TokenStream) -> TokenStream {
         // Handle #[builder(skip)] and #[builder(skip = ...)]
         if let Some(skip) = get_skip_expr(&f.attrs) {
             if let Some(expr) = skip {
-                // Skipped with a value: set in build_fields
-                build_fields.push(quote! {
+                // Skipped with a value: set directly in the struct literal
+                struct_fields.push(quote! {
                     #name: #expr
                 });
                 skipped_with_value.push(name);
@@ -50,22 +85,66 @@ pub fn expand_autobuilder(input: TokenStream) -> TokenStream {
             }
             continue;
         }
-        field_idents.push(name);
+        let validate_path = get_validate_path(&f.attrs);
+
+        // `#[builder(field(type = "...", build = "..."))]` replaces the
+        // usual `Option<FieldTy>` storage and unwrap/default logic entirely,
+        // so it's checked ahead of the Vec/Option/regular branches below.
+        if let Some(custom) = get_custom_field(&f.attrs) {
+            let custom_ty = &custom.ty;
+            let build_expr = &custom.build_expr;
+            let setter_name = get_setter_name(&f.attrs, name.as_ref().unwrap());
+            builder_fields.push(quote! { #name: #custom_ty });
+            new_field_inits.push(quote! { #name: Default::default() });
+            setters.push(quote! {
+                pub fn #setter_name(&mut self, value: #custom_ty) -> &mut Self {
+                    self.#name = value;
+                    self
+                }
+            });
+            value_bindings.push(quote! {
+                let #name = #build_expr;
+            });
+            if let Some(path) = &validate_path {
+                let map_err = validation_map_err(legacy_error);
+                value_bindings.push(quote! { #path(&#name)#map_err; });
+            }
+            struct_fields.push(quote! { #name });
+            continue;
+        }
+
+        new_field_inits.push(quote! { #name: None });
         // Handle Vec fields with special push/set/extend setters
         if let Some(inner_ty) = is_vec(ty) {
             // Generate three methods: push (add_item), extend (add_items), set (set_items)
             let push_name = get_setter_push_name(&f.attrs, &format_ident!("add_item"));
             let push_many_name = get_setter_push_many_name(&f.attrs, &format_ident!("add_items"));
             let set_name = get_setter_set_name(&f.attrs, &format_ident!("items"));
+            let into = get_setter_into(&f.attrs, struct_default_into);
             builder_fields.push(quote! { #name: Option<Vec<#inner_ty>> });
-            setters.push(quote! {
-                pub fn #push_name(&mut self, value: #inner_ty) -> &mut Self {
-                    if self.#name.is_none() {
-                        self.#name = Some(Vec::new());
+            let push_setter = if into {
+                quote! {
+                    pub fn #push_name(&mut self, value: impl Into<#inner_ty>) -> &mut Self {
+                        if self.#name.is_none() {
+                            self.#name = Some(Vec::new());
+                        }
+                        self.#name.as_mut().unwrap().push(value.into());
+                        self
+                    }
+                }
+            } else {
+                quote! {
+                    pub fn #push_name(&mut self, value: #inner_ty) -> &mut Self {
+                        if self.#name.is_none() {
+                            self.#name = Some(Vec::new());
+                        }
+                        self.#name.as_mut().unwrap().push(value);
+                        self
                     }
-                    self.#name.as_mut().unwrap().push(value);
-                    self
                 }
+            };
+            setters.push(quote! {
+                #push_setter
                 pub fn #push_many_name(&mut self, values: Vec<#inner_ty>) -> &mut Self {
                     if self.#name.is_none() {
                         self.#name = Some(Vec::new());
@@ -79,125 +158,404 @@ pub fn expand_autobuilder(input: TokenStream) -> TokenStream {
                 }
             });
             // In build(), use .unwrap_or_default() for Vec fields
-            build_fields.push(quote! {
-                #name: self.#name.clone().unwrap_or_default()
+            value_bindings.push(quote! {
+                let #name = self.#name.clone().unwrap_or_default();
             });
+            if let Some(path) = &validate_path {
+                let map_err = validation_map_err(legacy_error);
+                value_bindings.push(quote! { #path(&#name)#map_err; });
+            }
+            struct_fields.push(quote! { #name });
         } else if is_option(ty) {
             // Handle Option<T> fields: setter sets Some(value), build uses unwrap_or(None)
             let inner_ty = option_inner_type(ty).unwrap();
             // Support #[builder(setter = ...)] for Option fields
-            let setter_name = {
-                let mut setter_name = None;
-                for attr in &f.attrs {
-                    if attr.path().is_ident("builder") {
-                        if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
-                            for meta in punct {
-                                if meta.path().is_ident("setter") {
-                                    if let syn::Meta::NameValue(ref nv) = meta {
-                                        if let syn::Expr::Lit(expr_lit) = &nv.value {
-                                            if let syn::Lit::Str(litstr) = &expr_lit.lit {
-                                                setter_name = Some(format_ident!("{}", litstr.value()));
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                setter_name.unwrap_or_else(|| name.as_ref().unwrap().clone())
-            };
+            let setter_name = get_setter_name(&f.attrs, name.as_ref().unwrap());
+            let into = get_setter_into(&f.attrs, struct_default_into);
             builder_fields.push(quote! { #name: Option<#ty> });
-            setters.push(quote! {
-                pub fn #setter_name(&mut self, value: #inner_ty) -> &mut Self {
-                    self.#name = Some(Some(value));
-                    self
-                }
-            });
-            build_fields.push(quote! {
-                #name: self.#name.clone().unwrap_or(None)
+            if into {
+                setters.push(quote! {
+                    pub fn #setter_name(&mut self, value: impl Into<#inner_ty>) -> &mut Self {
+                        self.#name = Some(Some(value.into()));
+                        self
+                    }
+                });
+            } else {
+                setters.push(quote! {
+                    pub fn #setter_name(&mut self, value: #inner_ty) -> &mut Self {
+                        self.#name = Some(Some(value));
+                        self
+                    }
+                });
+            }
+            if has_setter_try_into_flag(&f.attrs) {
+                let try_name = get_setter_try_name(&f.attrs, name.as_ref().unwrap());
+                setters.push(quote! {
+                    pub fn #try_name<V>(&mut self, value: V) -> Result<&mut Self, V::Error>
+                    where
+                        V: TryInto<#inner_ty>,
+                    {
+                        self.#name = Some(Some(value.try_into()?));
+                        Ok(self)
+                    }
+                });
+            }
+            value_bindings.push(quote! {
+                let #name = self.#name.clone().unwrap_or(None);
             });
+            if let Some(path) = &validate_path {
+                let map_err = validation_map_err(legacy_error);
+                value_bindings.push(quote! { #path(&#name)#map_err; });
+            }
+            struct_fields.push(quote! { #name });
         } else {
             // Handle regular fields (required or with default)
-            // Parse all builder keys in one pass: setter, default, etc.
-            let mut setter_name = None;
-            let mut default_expr = None;
-            for attr in &f.attrs {
-                if attr.path().is_ident("builder") {
-                    if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
-                        for meta in punct {
-                            if meta.path().is_ident("setter") {
-                                if let syn::Meta::NameValue(ref nv) = meta {
-                                    if let syn::Expr::Lit(expr_lit) = &nv.value {
-                                        if let syn::Lit::Str(litstr) = &expr_lit.lit {
-                                            setter_name = Some(format_ident!("{}", litstr.value()));
-                                        }
-                                    }
-                                }
-                            }
-                            if meta.path().is_ident("default") {
-                                if let syn::Meta::NameValue(ref nv) = meta {
-                                    default_expr = Some(nv.value.clone());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            let setter_name = setter_name.unwrap_or_else(|| name.as_ref().unwrap().clone());
+            let setter_name = get_setter_name(&f.attrs, name.as_ref().unwrap());
+            let default_expr = get_default_expr(&f.attrs);
+            let into = get_setter_into(&f.attrs, struct_default_into);
             // Generate the setter method for this field
             builder_fields.push(quote! { #name: Option<#ty> });
-            setters.push(quote! {
-                pub fn #setter_name(&mut self, value: #ty) -> &mut Self {
-                    self.#name = Some(value);
-                    self
-                }
-            });
+            if into {
+                setters.push(quote! {
+                    pub fn #setter_name(&mut self, value: impl Into<#ty>) -> &mut Self {
+                        self.#name = Some(value.into());
+                        self
+                    }
+                });
+            } else {
+                setters.push(quote! {
+                    pub fn #setter_name(&mut self, value: #ty) -> &mut Self {
+                        self.#name = Some(value);
+                        self
+                    }
+                });
+            }
+            if has_setter_try_into_flag(&f.attrs) {
+                let try_name = get_setter_try_name(&f.attrs, name.as_ref().unwrap());
+                setters.push(quote! {
+                    pub fn #try_name<V>(&mut self, value: V) -> Result<&mut Self, V::Error>
+                    where
+                        V: TryInto<#ty>,
+                    {
+                        self.#name = Some(value.try_into()?);
+                        Ok(self)
+                    }
+                });
+            }
             // In build(), use default if present, else require the field
             if let Some(expr) = default_expr {
-                build_fields.push(quote! {
-                    #name: self.#name.clone().unwrap_or_else(|| #expr)
+                value_bindings.push(quote! {
+                    let #name = self.#name.clone().unwrap_or_else(|| #expr);
+                });
+            } else if legacy_error {
+                value_bindings.push(quote! {
+                    let #name = self.#name.clone().ok_or_else(|| format!("Field '{}' is missing", stringify!(#name)))?;
                 });
             } else {
-                build_fields.push(quote! {
-                    #name: self.#name.clone().ok_or_else(|| format!("Field '{}' is missing", stringify!(#name)))?
+                required_field_names.push(name.clone());
+                value_bindings.push(quote! {
+                    let #name = self.#name.clone().unwrap();
                 });
             }
+            if let Some(path) = &validate_path {
+                let map_err = validation_map_err(legacy_error);
+                value_bindings.push(quote! { #path(&#name)#map_err; });
+            }
+            struct_fields.push(quote! { #name });
         }
     }
 
-    // Compose the build() method, using Default if any fields were skipped without a value
-    let build_struct = if !skipped_without_value.is_empty() {
+    // Assemble the final struct literal, falling back to Default for fields
+    // skipped without a value.
+    let struct_literal = if !skipped_without_value.is_empty() {
         quote! {
-            Ok(#struct_name {
-                #(#build_fields,)*
+            #struct_name {
+                #(#struct_fields,)*
                 ..Default::default()
-            })
+            }
         }
     } else {
         quote! {
-            Ok(#struct_name {
-                #(#build_fields,)*
-            })
+            #struct_name {
+                #(#struct_fields,)*
+            }
+        }
+    };
+
+    // `#[builder(validate = "...")]` on the struct runs once all fields are
+    // assembled, and can still reject the build.
+    let struct_validate_path = get_validate_path(&input.attrs);
+    let struct_validate_stmt = struct_validate_path.as_ref().map(|path| {
+        let map_err = validation_map_err(legacy_error);
+        quote! { #path(&built)#map_err; }
+    });
+
+    // `#[builder(perform = "...")]` (or `perform(path = "...", output =
+    // "..."))]` pipes the assembled struct through a finishing function
+    // instead of returning it directly; its signature is `fn(#struct_name)
+    // -> Result<R, E>`, so `build()`'s success type becomes `R` (the struct
+    // itself when `output` isn't given) and its error type adapts to `E`
+    // the same way a validation hook's would.
+    let perform_hook = get_perform_path(&input.attrs);
+    let build_success_ty = match perform_hook.as_ref().and_then(|hook| hook.output_ty.as_ref()) {
+        Some(ty) => quote! { #ty },
+        None => quote! { #struct_name },
+    };
+    let tail = if let Some(hook) = &perform_hook {
+        let path = &hook.path;
+        if legacy_error {
+            quote! { #path(built).map_err(|e| e.to_string()) }
+        } else {
+            quote! { #path(built).map_err(|e| #builder_error_name::Validation(e.to_string())) }
+        }
+    } else {
+        quote! { Ok(built) }
+    };
+    let assemble_and_return = quote! {
+        let built = #struct_literal;
+        #struct_validate_stmt
+        #tail
+    };
+
+    let build_method = if legacy_error {
+        // Compatibility mode: bail on the first missing field via `?`.
+        quote! {
+            pub fn build(&self) -> Result<#build_success_ty, String> {
+                #(#value_bindings)*
+                #assemble_and_return
+            }
+        }
+    } else {
+        // Default mode: collect every missing required field before failing,
+        // so callers see the whole picture instead of one field at a time.
+        let missing_checks = required_field_names.iter().map(|name| {
+            quote! {
+                if self.#name.is_none() {
+                    missing.push(stringify!(#name));
+                }
+            }
+        });
+        quote! {
+            pub fn build(&self) -> Result<#build_success_ty, #builder_error_name> {
+                let mut missing: Vec<&'static str> = Vec::new();
+                #(#missing_checks)*
+                if !missing.is_empty() {
+                    return Err(#builder_error_name::MissingFields(missing));
+                }
+                #(#value_bindings)*
+                #assemble_and_return
+            }
+        }
+    };
+
+    // The generated error type is only needed in the default (non-legacy) mode.
+    let error_type = if legacy_error {
+        quote! {}
+    } else {
+        quote! {
+            #[derive(Debug)]
+            pub enum #builder_error_name {
+                MissingFields(Vec<&'static str>),
+                Validation(String),
+            }
+
+            impl std::fmt::Display for #builder_error_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        #builder_error_name::MissingFields(fields) => {
+                            write!(f, "missing required field(s): {}", fields.join(", "))
+                        }
+                        #builder_error_name::Validation(msg) => write!(f, "{}", msg),
+                    }
+                }
+            }
+
+            impl std::error::Error for #builder_error_name {}
+
+            // Lets `?` be used directly inside a `#[builder(field(build = "..."))]`
+            // expression (or any other hand-written fallible step) as long as it
+            // produces a `String` error, the same convention `validate` hooks use.
+            impl From<String> for #builder_error_name {
+                fn from(e: String) -> Self {
+                    #builder_error_name::Validation(e)
+                }
+            }
         }
     };
 
     // Generate the builder struct and its impl
     let expanded = quote! {
+        #error_type
         pub struct #builder_name {
             #(#builder_fields,)*
         }
         impl #builder_name {
             pub fn new() -> Self {
                 Self {
-                    #(#field_idents: None,)*
+                    #(#new_field_inits,)*
                 }
             }
             #(#setters)*
-            pub fn build(&self) -> Result<#struct_name, String> {
-                #build_struct
+            #build_method
+        }
+    };
+    TokenStream::from(expanded)
+}
+
+/// Code generation for `#[builder(typestate)]`.
+///
+/// Every non-`Option`, non-`Vec`, non-defaulted field is "gated": it gets its
+/// own const-generic `bool` parameter on the builder (starting `false` in
+/// `new()`), and the matching setter consumes `self` and returns the builder
+/// retyped with that one parameter flipped to `true`. `build()` is only ever
+/// emitted in the `impl` block where every gated parameter is `true`, so
+/// forgetting a required field is a compile error instead of a runtime one,
+/// and `build()` can return `#struct_name` directly instead of a `Result`.
+/// `Option`/`Vec`/defaulted fields don't participate in gating: their
+/// setters just consume and return `Self` unchanged.
+fn expand_typestate_builder(
+    struct_name: Ident,
+    builder_name: Ident,
+    fields: &Punctuated<syn::Field, Comma>,
+) -> TokenStream {
+    let mut builder_fields = Vec::new(); // Fields in the builder struct
+    let mut field_idents = Vec::new();   // All stored field names, for new() and struct literals
+    let mut gated_fields = Vec::new();   // (field name, const-generic param name) for required fields
+    let mut fixed_setters = Vec::new();  // Setters that don't change the builder's type
+    let mut gated_setters = Vec::new();  // Setters that flip one const-generic param to true
+    let mut build_fields = Vec::new();   // Fields for the final build() call
+
+    for f in fields.iter() {
+        let name = &f.ident;
+        let ty = &f.ty;
+        if let Some(skip) = get_skip_expr(&f.attrs) {
+            let expr = skip.unwrap_or_else(|| syn::parse_quote!(Default::default()));
+            build_fields.push(quote! { #name: #expr });
+            continue;
+        }
+        field_idents.push(name.clone());
+
+        if let Some(inner_ty) = is_vec(ty) {
+            let push_name = get_setter_push_name(&f.attrs, &format_ident!("add_item"));
+            let push_many_name = get_setter_push_many_name(&f.attrs, &format_ident!("add_items"));
+            let set_name = get_setter_set_name(&f.attrs, &format_ident!("items"));
+            builder_fields.push(quote! { #name: Option<Vec<#inner_ty>> });
+            fixed_setters.push(quote! {
+                pub fn #push_name(mut self, value: #inner_ty) -> Self {
+                    self.#name.get_or_insert_with(Vec::new).push(value);
+                    self
+                }
+                pub fn #push_many_name(mut self, values: Vec<#inner_ty>) -> Self {
+                    self.#name.get_or_insert_with(Vec::new).extend(values);
+                    self
+                }
+                pub fn #set_name(mut self, value: Vec<#inner_ty>) -> Self {
+                    self.#name = Some(value);
+                    self
+                }
+            });
+            build_fields.push(quote! { #name: self.#name.unwrap_or_default() });
+        } else if is_option(ty) {
+            let inner_ty = option_inner_type(ty).unwrap();
+            let setter_name = get_setter_name(&f.attrs, name.as_ref().unwrap());
+            builder_fields.push(quote! { #name: Option<#ty> });
+            fixed_setters.push(quote! {
+                pub fn #setter_name(mut self, value: #inner_ty) -> Self {
+                    self.#name = Some(Some(value));
+                    self
+                }
+            });
+            build_fields.push(quote! { #name: self.#name.unwrap_or(None) });
+        } else {
+            let setter_name = get_setter_name(&f.attrs, name.as_ref().unwrap());
+            let default_expr = get_default_expr(&f.attrs);
+            builder_fields.push(quote! { #name: Option<#ty> });
+            if let Some(expr) = default_expr {
+                fixed_setters.push(quote! {
+                    pub fn #setter_name(mut self, value: #ty) -> Self {
+                        self.#name = Some(value);
+                        self
+                    }
+                });
+                build_fields.push(quote! { #name: self.#name.unwrap_or_else(|| #expr) });
+            } else {
+                let param = format_ident!("F{}", gated_fields.len());
+                gated_fields.push((name.clone(), param, setter_name, ty.clone()));
+                build_fields.push(quote! { #name: self.#name.unwrap() });
+            }
+        }
+    }
+
+    let gated_params: Vec<Ident> = gated_fields.iter().map(|(_, p, _, _)| p.clone()).collect();
+
+    // Every gated setter flips its own parameter to `true` and copies the rest
+    // of the builder's state verbatim, since `Self` changes type as soon as
+    // one parameter is retyped.
+    for (i, (name, _, setter_name, ty)) in gated_fields.iter().enumerate() {
+        let target_params: Vec<_> = gated_params
+            .iter()
+            .enumerate()
+            .map(|(j, p)| if j == i { quote! { true } } else { quote! { #p } })
+            .collect();
+        let field_copies = field_idents.iter().map(|fname| {
+            if fname == name {
+                quote! { #fname: Some(value) }
+            } else {
+                quote! { #fname: self.#fname }
+            }
+        });
+        gated_setters.push(quote! {
+            pub fn #setter_name(self, value: #ty) -> #builder_name<#(#target_params),*> {
+                #builder_name {
+                    #(#field_copies,)*
+                }
+            }
+        });
+    }
+
+    let all_false = gated_params.iter().map(|_| quote! { false });
+    let all_true = gated_params.iter().map(|_| quote! { true });
+
+    let (generic_decl, generic_use_false, generic_use_true) = if gated_params.is_empty() {
+        (quote! {}, quote! {}, quote! {})
+    } else {
+        (
+            quote! { <#(const #gated_params: bool),*> },
+            quote! { <#(#all_false),*> },
+            quote! { <#(#all_true),*> },
+        )
+    };
+    let generic_use_any = if gated_params.is_empty() {
+        quote! {}
+    } else {
+        quote! { <#(#gated_params),*> }
+    };
+
+    let expanded = quote! {
+        pub struct #builder_name #generic_decl {
+            #(#builder_fields,)*
+        }
+
+        impl #generic_decl #builder_name #generic_use_any {
+            #(#fixed_setters)*
+            #(#gated_setters)*
+        }
+
+        impl #builder_name #generic_use_false {
+            pub fn new() -> Self {
+                Self {
+                    #(#field_idents: None,)*
+                }
+            }
+        }
+
+        impl #builder_name #generic_use_true {
+            pub fn build(self) -> #struct_name {
+                #struct_name {
+                    #(#build_fields,)*
+                }
             }
         }
     };
     TokenStream::from(expanded)
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -2,8 +2,29 @@
 // Attribute and field parsing helpers for AutoBuilder proc macro.
 
 use syn::{Attribute, Expr, Type, PathArguments, Lit};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
 use quote::format_ident;
 
+/// A single `key = value` entry inside `#[builder(field(...))]`. Parsed by
+/// hand (rather than via `syn::Meta`) because `type` is a reserved keyword
+/// and `syn::Meta`'s path parser rejects it as an identifier. `value` is
+/// kept as a full `Expr`, not just a `Lit`, so an unrecognized key with a
+/// non-string value doesn't abort parsing of the whole list.
+struct FieldKeyValue {
+    key: syn::Ident,
+    value: Expr,
+}
+
+impl Parse for FieldKeyValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = syn::Ident::parse_any(input)?;
+        input.parse::<syn::Token![=]>()?;
+        let value = input.parse()?;
+        Ok(FieldKeyValue { key, value })
+    }
+}
+
 /// Returns true if the type is Option<T>
 pub fn is_option(ty: &Type) -> bool {
     if let Type::Path(type_path) = ty {
@@ -76,6 +97,310 @@ pub fn get_skip_expr(attrs: &[Attribute]) -> Option<Option<Expr>> {
     None
 }
 
+/// Returns true if the struct carries `#[builder(typestate)]`, opting into the
+/// const-generic typestate builder (compile-time required-field enforcement)
+/// instead of the default runtime-checked builder.
+pub fn is_typestate(attrs: &[Attribute]) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("typestate") {
+                    found = true;
+                }
+                Ok(())
+            });
+            if found {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Parses `#[builder(setter = "...")]`, returning the override setter name,
+/// or `default` (the field's own name) if not present.
+pub fn get_setter_name(attrs: &[Attribute], default: &syn::Ident) -> syn::Ident {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("setter") {
+                        if let syn::Meta::NameValue(ref nv) = meta {
+                            if let syn::Expr::Lit(expr_lit) = &nv.value {
+                                if let syn::Lit::Str(litstr) = &expr_lit.lit {
+                                    return format_ident!("{}", litstr.value());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    default.clone()
+}
+
+/// Parses `#[builder(default = expr)]`, returning the default expression if present.
+pub fn get_default_expr(attrs: &[Attribute]) -> Option<Expr> {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("default") {
+                        if let syn::Meta::NameValue(ref nv) = meta {
+                            return Some(nv.value.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns true if `#[builder(setter(into))]` is present on these attrs,
+/// whether on a field (`#[builder(setter(into))]`) or a struct
+/// (`#[builder(setter(into))]` as the struct-level default).
+pub fn has_setter_into_flag(attrs: &[Attribute]) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("setter") {
+                        if let syn::Meta::List(ref list) = meta {
+                            if let Ok(inner) = list.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                                for m in inner {
+                                    if m.path().is_ident("into") {
+                                        return true;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Whether a field's setter should accept `impl Into<T>`: the field-level
+/// `#[builder(setter(into))]` wins, falling back to the struct-level default.
+pub fn get_setter_into(field_attrs: &[Attribute], struct_default_into: bool) -> bool {
+    struct_default_into || has_setter_into_flag(field_attrs)
+}
+
+/// Returns true if `#[builder(setter(try_into))]` (or `setter(try_setter)`)
+/// is present on a field, requesting an additional fallible `try_<field>`
+/// setter alongside the regular one.
+pub fn has_setter_try_into_flag(attrs: &[Attribute]) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("setter") {
+                        if let syn::Meta::List(ref list) = meta {
+                            if let Ok(inner) = list.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                                for m in inner {
+                                    if m.path().is_ident("try_into") || m.path().is_ident("try_setter") {
+                                        return true;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Parses `#[builder(setter_try = "...")]` or returns `try_{field}` as default.
+pub fn get_setter_try_name(attrs: &[Attribute], default: &syn::Ident) -> syn::Ident {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            let mut setter_name = None;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("setter_try") {
+                    if let Ok(val) = meta.value() {
+                        if let Ok(Lit::Str(litstr)) = val.parse() {
+                            setter_name = Some(format_ident!("{}", litstr.value()));
+                        }
+                    }
+                }
+                Ok(())
+            });
+            if let Some(name) = setter_name {
+                return name;
+            }
+        }
+    }
+    format_ident!("try_{}", default)
+}
+
+/// Returns true if the struct carries `#[builder(legacy_error)]`, opting
+/// back into the old `build(&self) -> Result<Self, String>` signature for
+/// callers that haven't migrated to the generated `*BuilderError` type yet.
+pub fn is_legacy_error(attrs: &[Attribute]) -> bool {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("legacy_error") {
+                    found = true;
+                }
+                Ok(())
+            });
+            if found {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Parses `#[builder(validate = "path::to::fn")]` at either the field or
+/// struct level, returning the referenced function's path if present. The
+/// function is expected to have signature `fn(&T) -> Result<(), E>` for a
+/// field, or `fn(&Struct) -> Result<(), E>` for the struct-level hook.
+pub fn get_validate_path(attrs: &[Attribute]) -> Option<syn::Path> {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("validate") {
+                        if let syn::Meta::NameValue(ref nv) = meta {
+                            if let syn::Expr::Lit(expr_lit) = &nv.value {
+                                if let syn::Lit::Str(litstr) = &expr_lit.lit {
+                                    if let Ok(path) = syn::parse_str::<syn::Path>(&litstr.value()) {
+                                        return Some(path);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// A field overridden via `#[builder(field(type = "...", build = "..."))]`:
+/// the builder stores `ty` (which must implement `Default`) instead of
+/// `Option<FieldTy>`, and `build_expr` computes the final field value.
+pub struct CustomField {
+    pub ty: Type,
+    pub build_expr: Expr,
+}
+
+/// Parses `#[builder(field(type = "SomeType", build = "self.x.parse().map_err(|e: ParseIntError| e.to_string())?"))]`,
+/// borrowed from derive_builder's "completely custom fields" support. The
+/// `build` expression is spliced into `build()` as-is, so a fallible step
+/// must resolve its error to `String` (e.g. via `.map_err(|e| e.to_string())`)
+/// to work with `?`, the same convention `validate` hooks use.
+pub fn get_custom_field(attrs: &[Attribute]) -> Option<CustomField> {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("field") {
+                        if let syn::Meta::List(ref list) = meta {
+                            if let Ok(inner) = list.parse_args_with(syn::punctuated::Punctuated::<FieldKeyValue, syn::Token![,]>::parse_terminated) {
+                                let mut ty = None;
+                                let mut build_expr = None;
+                                for kv in inner {
+                                    if let Expr::Lit(expr_lit) = &kv.value {
+                                        if let Lit::Str(litstr) = &expr_lit.lit {
+                                            if kv.key == "type" {
+                                                ty = syn::parse_str::<Type>(&litstr.value()).ok();
+                                            } else if kv.key == "build" {
+                                                build_expr = syn::parse_str::<Expr>(&litstr.value()).ok();
+                                            }
+                                        }
+                                    }
+                                }
+                                if let (Some(ty), Some(build_expr)) = (ty, build_expr) {
+                                    return Some(CustomField { ty, build_expr });
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The struct-level `#[builder(perform = ...)]` hook: once the struct is
+/// assembled, `build()` pipes it through `path` instead of returning it
+/// directly. `output_ty` is `None` for the plain `perform = "path::to::fn"`
+/// form, where the function is assumed to return `Result<Struct, E>`; it's
+/// `Some(ty)` for `perform(path = "...", output = "...")`, where the
+/// function returns `Result<ty, E>` instead. Either way `E` isn't named
+/// here — `build()` maps it through the same `.to_string()` convention
+/// `validate` hooks use, so it adapts without macro-side type info.
+pub struct PerformHook {
+    pub path: syn::Path,
+    pub output_ty: Option<Type>,
+}
+
+/// Parses the struct-level `#[builder(perform = "path::to::fn")]` hook,
+/// inspired by the `form` crate's "after" concept, along with its
+/// `#[builder(perform(path = "...", output = "..."))]` form for a finishing
+/// function that produces something other than the struct itself (e.g.
+/// opening a connection, registering the object) — `output` names that
+/// return type, the same way `#[builder(field(type = "...", ...))]` names a
+/// type the macro otherwise has no way to see.
+pub fn get_perform_path(attrs: &[Attribute]) -> Option<PerformHook> {
+    for attr in attrs {
+        if attr.path().is_ident("builder") {
+            if let Ok(punct) = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                for meta in punct {
+                    if meta.path().is_ident("perform") {
+                        match &meta {
+                            syn::Meta::NameValue(nv) => {
+                                if let Expr::Lit(expr_lit) = &nv.value {
+                                    if let Lit::Str(litstr) = &expr_lit.lit {
+                                        if let Ok(path) = syn::parse_str::<syn::Path>(&litstr.value()) {
+                                            return Some(PerformHook { path, output_ty: None });
+                                        }
+                                    }
+                                }
+                            }
+                            syn::Meta::List(list) => {
+                                if let Ok(inner) = list.parse_args_with(syn::punctuated::Punctuated::<FieldKeyValue, syn::Token![,]>::parse_terminated) {
+                                    let mut path = None;
+                                    let mut output_ty = None;
+                                    for kv in inner {
+                                        if let Expr::Lit(expr_lit) = &kv.value {
+                                            if let Lit::Str(litstr) = &expr_lit.lit {
+                                                if kv.key == "path" {
+                                                    path = syn::parse_str::<syn::Path>(&litstr.value()).ok();
+                                                } else if kv.key == "output" {
+                                                    output_ty = syn::parse_str::<Type>(&litstr.value()).ok();
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if let Some(path) = path {
+                                        return Some(PerformHook { path, output_ty });
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
 // --- Vec setter name helpers (for gen.rs) ---
 
 /// Parses #[builder(setter_set = ...)] or returns set_{field} as default